@@ -1,12 +1,149 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{self, Write};
 use std::path::Path;
 use printpdf::*;
-use chrono::Local;
+use chrono::{Local, NaiveDate};
+use std::collections::BTreeMap;
 
-const DATA_FILE: &str = "jobs.json";
+const JSON_DATA_FILE: &str = "jobs.json";
+const MSGPACK_DATA_FILE: &str = "jobs.msgpack";
+const DEFAULT_STALE_DAYS: i64 = 14;
+
+/// Storage backend used to persist `jobs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+enum Format {
+    #[default]
+    Json,
+    Msgpack,
+}
+
+/// Path of the data file for `format`.
+fn data_file_path(format: Format) -> &'static str {
+    match format {
+        Format::Json => JSON_DATA_FILE,
+        Format::Msgpack => MSGPACK_DATA_FILE,
+    }
+}
+
+/// Path of the rotating backup snapshot for `path`.
+fn backup_path(path: &str) -> String {
+    format!("{}.bak", path)
+}
+
+/// Picks the storage backend to use: the explicit `--format` flag if given,
+/// otherwise auto-detected from whichever data file already exists on disk.
+fn resolve_format(explicit: Option<Format>) -> Format {
+    if let Some(format) = explicit {
+        return format;
+    }
+    if Path::new(MSGPACK_DATA_FILE).exists() {
+        Format::Msgpack
+    } else {
+        Format::Json
+    }
+}
+
+/// The stage of a job application in the hiring pipeline.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    #[default]
+    Applied,
+    Screening,
+    Interviewing,
+    Offer,
+    Rejected,
+    Accepted,
+    Withdrawn,
+}
+
+const ALL_STATUSES: [Status; 7] = [
+    Status::Applied,
+    Status::Screening,
+    Status::Interviewing,
+    Status::Offer,
+    Status::Rejected,
+    Status::Accepted,
+    Status::Withdrawn,
+];
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Status::Applied => "Applied",
+            Status::Screening => "Screening",
+            Status::Interviewing => "Interviewing",
+            Status::Offer => "Offer",
+            Status::Rejected => "Rejected",
+            Status::Accepted => "Accepted",
+            Status::Withdrawn => "Withdrawn",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Status {
+    /// A job is no longer actively moving once it lands in one of these states.
+    fn is_terminal(self) -> bool {
+        matches!(self, Status::Rejected | Status::Accepted | Status::Withdrawn)
+    }
+
+    /// Best-effort mapping from the free-text `final_answer` values older
+    /// records (and CSV imports) used, before `Status` existed.
+    fn from_legacy_answer(raw: &str) -> Status {
+        let lower = raw.to_lowercase();
+        // Terminal/negative wording wins even when the text also mentions an
+        // earlier stage (e.g. "Rejected after the interview round" must land
+        // on Rejected, not Interviewing).
+        if lower.contains("reject") || lower.contains("declin") || lower.contains("no offer") || lower.contains("ghost") {
+            Status::Rejected
+        } else if lower.contains("withdraw") || lower.contains("withdrew") {
+            Status::Withdrawn
+        } else if lower.contains("accept") {
+            Status::Accepted
+        } else if lower.contains("offer") {
+            Status::Offer
+        } else if lower.contains("interview") {
+            Status::Interviewing
+        } else if lower.contains("screen") {
+            Status::Screening
+        } else {
+            // Anything else ("No", ...) reads as a closed-out, non-successful outcome.
+            Status::Rejected
+        }
+    }
+}
+
+/// Returns true if moving from `from` to `to` is a sensible pipeline transition.
+///
+/// Terminal states (`Rejected`/`Accepted`/`Withdrawn`) cannot be left without
+/// `--force`, and moving back to an earlier stage is likewise only allowed
+/// when forced, except for `Interviewing -> Screening`.
+fn is_valid_transition(from: Status, to: Status) -> bool {
+    use Status::*;
+    if from == to {
+        return false;
+    }
+    matches!(
+        (from, to),
+        (Applied, Screening | Interviewing | Offer | Rejected | Withdrawn)
+            | (Screening, Interviewing | Offer | Rejected | Withdrawn)
+            | (Interviewing, Offer | Rejected | Withdrawn | Screening)
+            | (Offer, Accepted | Rejected | Withdrawn)
+    )
+}
+
+/// One recorded move through the hiring pipeline.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct StatusEvent {
+    from: Option<Status>,
+    to: Status,
+    date: String,
+    note: Option<String>,
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct Job {
@@ -16,13 +153,197 @@ struct Job {
     date_submitted: String,
     docs_used: String,
     location: String,
-    final_answer: Option<String>,
+    #[serde(default)]
+    status: Status,
+    #[serde(default)]
+    history: Vec<StatusEvent>,
+    // Old records stored a free-text final answer instead of a typed status.
+    // Kept around only long enough for `load_jobs` to migrate it into `history`.
+    #[serde(default, skip_serializing, rename = "final_answer")]
+    legacy_final_answer: Option<String>,
+}
+
+/// Applied -> interview -> offer conversion counts and rates, each counted
+/// as "ever reached this stage", independent of where the job ended up.
+#[derive(Serialize, Debug)]
+struct Funnel {
+    applied: usize,
+    interviewing: usize,
+    offer: usize,
+    interview_rate: f64,
+    offer_rate: f64,
+}
+
+/// A job that hasn't had a status change in at least `stale_days`.
+#[derive(Serialize, Debug)]
+struct StaleJob {
+    id: u32,
+    company: String,
+    title: String,
+    status: Status,
+    days_since_last_update: i64,
+}
+
+/// Aggregate analytics over a set of jobs, shared by the `stats` subcommand
+/// and the PDF export so the two never diverge.
+#[derive(Serialize, Debug)]
+struct Stats {
+    total: usize,
+    status_counts: BTreeMap<Status, usize>,
+    funnel: Funnel,
+    response_rate: f64,
+    avg_time_to_first_response_days: Option<f64>,
+    by_location: BTreeMap<String, usize>,
+    by_month: BTreeMap<String, usize>,
+    stale: Vec<StaleJob>,
+}
+
+/// True if `job` is currently at, or has ever passed through, `stage`.
+fn reached_stage(job: &Job, stage: Status) -> bool {
+    job.status == stage || job.history.iter().any(|e| e.to == stage)
+}
+
+/// The first history entry that moved a job off its initial `Applied` state,
+/// i.e. the employer's first response.
+fn first_response_event(job: &Job) -> Option<&StatusEvent> {
+    job.history.iter().find(|e| e.to != Status::Applied)
+}
+
+/// Computes the full analytics report for `jobs` as of `today`, flagging
+/// non-terminal jobs with no status change in at least `stale_days` as stale.
+fn compute_stats(jobs: &[Job], today: NaiveDate, stale_days: i64) -> Stats {
+    let total = jobs.len();
+
+    let mut status_counts: BTreeMap<Status, usize> = BTreeMap::new();
+    for job in jobs {
+        *status_counts.entry(job.status).or_insert(0) += 1;
+    }
+
+    let interviewing = jobs.iter().filter(|j| reached_stage(j, Status::Interviewing)).count();
+    let offer = jobs.iter().filter(|j| reached_stage(j, Status::Offer)).count();
+    let funnel = Funnel {
+        applied: total,
+        interviewing,
+        offer,
+        interview_rate: if total > 0 { interviewing as f64 / total as f64 } else { 0.0 },
+        offer_rate: if interviewing > 0 { offer as f64 / interviewing as f64 } else { 0.0 },
+    };
+
+    let mut responded = 0usize;
+    let mut response_days = Vec::new();
+    for job in jobs {
+        if let Some(event) = first_response_event(job) {
+            responded += 1;
+            if let (Ok(submitted), Ok(responded_on)) = (
+                NaiveDate::parse_from_str(&job.date_submitted, "%Y-%m-%d"),
+                NaiveDate::parse_from_str(&event.date, "%Y-%m-%d"),
+            ) {
+                response_days.push((responded_on - submitted).num_days());
+            }
+        }
+    }
+    let response_rate = if total > 0 { responded as f64 / total as f64 } else { 0.0 };
+    let avg_time_to_first_response_days = if response_days.is_empty() {
+        None
+    } else {
+        Some(response_days.iter().sum::<i64>() as f64 / response_days.len() as f64)
+    };
+
+    let mut by_location: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_month: BTreeMap<String, usize> = BTreeMap::new();
+    for job in jobs {
+        *by_location.entry(job.location.clone()).or_insert(0) += 1;
+        let month = NaiveDate::parse_from_str(&job.date_submitted, "%Y-%m-%d")
+            .map(|d| d.format("%Y-%m").to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+        *by_month.entry(month).or_insert(0) += 1;
+    }
+
+    let stale = jobs
+        .iter()
+        .filter(|job| !job.status.is_terminal())
+        .filter_map(|job| {
+            let last_date = job.history.last().map(|e| e.date.as_str()).unwrap_or(&job.date_submitted);
+            let last = NaiveDate::parse_from_str(last_date, "%Y-%m-%d").ok()?;
+            let days_since_last_update = (today - last).num_days();
+            if days_since_last_update >= stale_days {
+                Some(StaleJob {
+                    id: job.id,
+                    company: job.company.clone(),
+                    title: job.title.clone(),
+                    status: job.status,
+                    days_since_last_update,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    Stats {
+        total,
+        status_counts,
+        funnel,
+        response_rate,
+        avg_time_to_first_response_days,
+        by_location,
+        by_month,
+        stale,
+    }
+}
+
+/// Prints `stats` as a human-readable analytics report.
+fn print_stats(stats: &Stats) {
+    println!("Total applications: {}", stats.total);
+    println!();
+    println!("By status:");
+    for status in ALL_STATUSES {
+        let count = stats.status_counts.get(&status).copied().unwrap_or(0);
+        println!("  {:<15} {}", status.to_string(), count);
+    }
+    println!();
+    println!("Funnel:");
+    println!("  Applied        {}", stats.funnel.applied);
+    println!("  Interviewing   {} ({:.1}%)", stats.funnel.interviewing, stats.funnel.interview_rate * 100.0);
+    println!("  Offer          {} ({:.1}%)", stats.funnel.offer, stats.funnel.offer_rate * 100.0);
+    println!();
+    println!("Response rate: {:.1}%", stats.response_rate * 100.0);
+    match stats.avg_time_to_first_response_days {
+        Some(days) => println!("Avg time to first response: {:.1} days", days),
+        None => println!("Avg time to first response: n/a"),
+    }
+    println!();
+    println!("By location:");
+    for (location, count) in &stats.by_location {
+        println!("  {:<20} {}", location, count);
+    }
+    println!();
+    println!("By month:");
+    for (month, count) in &stats.by_month {
+        println!("  {:<10} {}", month, count);
+    }
+    println!();
+    if stats.stale.is_empty() {
+        println!("No stale applications.");
+    } else {
+        println!("Stale applications (no status change in a while):");
+        for job in &stats.stale {
+            println!(
+                "  #{:<4} {} - {} [{}] ({} days)",
+                job.id, job.company, job.title, job.status, job.days_since_last_update
+            );
+        }
+    }
 }
 
 #[derive(Parser)]
 #[command(name = "job-tracker")]
 #[command(about = "A CLI tool to track job applications")]
 struct Cli {
+    /// Storage backend to use (defaults to auto-detecting from whichever
+    /// data file already exists, falling back to json)
+    #[arg(long, global = true, value_enum)]
+    format: Option<Format>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -37,12 +358,19 @@ enum Commands {
         location: String,
         date: Option<String>,
     },
-    /// Update a job application (specifically final answer)
-    Update {
+    /// Move a job application to a new status
+    Status {
         #[arg(long)]
         id: u32,
-        #[arg(short, long)]
-        answer: String,
+        #[arg(long)]
+        to: Status,
+        #[arg(long)]
+        note: Option<String>,
+        #[arg(long)]
+        date: Option<String>,
+        /// Allow transitions that don't follow the normal pipeline order
+        #[arg(long)]
+        force: bool,
     },
     /// Delete a job application
     Delete {
@@ -60,11 +388,31 @@ enum Commands {
     Import {
         file: String,
     },
+    /// Fuzzy-search job applications by company, title, location, or docs
+    Search {
+        query: String,
+        /// Maximum number of results to print
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+        /// Suppress matches scoring below this value
+        #[arg(long, default_value_t = 0.0)]
+        threshold: f64,
+    },
+    /// Print an analytics report: funnel, response time, and stale applications
+    Stats {
+        /// Print the report as JSON instead of a human-readable summary
+        #[arg(long)]
+        json: bool,
+        /// Flag non-terminal applications with no status change in this many days
+        #[arg(long, default_value_t = DEFAULT_STALE_DAYS)]
+        stale_days: i64,
+    },
 }
 
 fn main() -> io::Result<()> {
     let cli = Cli::parse();
-    let mut jobs = load_jobs()?;
+    let format = resolve_format(cli.format);
+    let mut jobs = load_jobs(format)?;
 
     match cli.command {
         Commands::Add {
@@ -80,20 +428,42 @@ fn main() -> io::Result<()> {
                 id,
                 company,
                 title,
-                date_submitted,
+                date_submitted: date_submitted.clone(),
                 docs_used: docs,
                 location,
-                final_answer: None,
+                status: Status::Applied,
+                history: vec![StatusEvent {
+                    from: None,
+                    to: Status::Applied,
+                    date: date_submitted,
+                    note: None,
+                }],
+                legacy_final_answer: None,
             };
             jobs.push(job.clone());
-            save_jobs(&jobs)?;
+            save_jobs(&jobs, format)?;
             println!("Added job: {} at {} (ID: {})", job.title, job.company, job.id);
         }
-        Commands::Update { id, answer } => {
+        Commands::Status { id, to, note, date, force } => {
             if let Some(job) = jobs.iter_mut().find(|j| j.id == id) {
-                job.final_answer = Some(answer.clone());
-                save_jobs(&jobs)?;
-                println!("Updated job {} with final answer: {}", id, answer);
+                let from = job.status;
+                if !force && !is_valid_transition(from, to) {
+                    println!(
+                        "Cannot transition job {} from {} to {} (use --force to override)",
+                        id, from, to
+                    );
+                } else {
+                    let date = date.unwrap_or_else(|| Local::now().format("%Y-%m-%d").to_string());
+                    job.history.push(StatusEvent {
+                        from: Some(from),
+                        to,
+                        date,
+                        note,
+                    });
+                    job.status = to;
+                    save_jobs(&jobs, format)?;
+                    println!("Job {} moved from {} to {}", id, from, to);
+                }
             } else {
                 println!("Job with ID {} not found.", id);
             }
@@ -102,7 +472,7 @@ fn main() -> io::Result<()> {
             let initial_len = jobs.len();
             jobs.retain(|j| j.id != id);
             if jobs.len() < initial_len {
-                save_jobs(&jobs)?;
+                save_jobs(&jobs, format)?;
                 println!("Deleted job: ID {}", id);
             } else {
                 println!("Job with ID {} not found.", id);
@@ -112,21 +482,27 @@ fn main() -> io::Result<()> {
             if jobs.is_empty() {
                 println!("No jobs tracked yet.");
             } else {
-                println!("{:<4} | {:<20} | {:<20} | {:<12} | {:<15} | {:<20} | {:<15}", 
-                    "ID", "Company", "Title", "Date", "Location", "Docs", "Answer");
+                let stats = compute_stats(&jobs, Local::now().date_naive(), DEFAULT_STALE_DAYS);
+                println!("{:<4} | {:<20} | {:<20} | {:<12} | {:<15} | {:<20} | {:<15}",
+                    "ID", "Company", "Title", "Date", "Location", "Docs", "Status");
                 println!("{}", "-".repeat(115));
                 for job in jobs {
-                    let answer = job.final_answer.clone().unwrap_or_else(|| "Pending".to_string());
-                    println!("{:<4} | {:<20} | {:<20} | {:<12} | {:<15} | {:<20} | {:<15}", 
-                        job.id, 
-                        truncate(&job.company, 20), 
-                        truncate(&job.title, 20), 
-                        job.date_submitted, 
+                    println!("{:<4} | {:<20} | {:<20} | {:<12} | {:<15} | {:<20} | {:<15}",
+                        job.id,
+                        truncate(&job.company, 20),
+                        truncate(&job.title, 20),
+                        job.date_submitted,
                         truncate(&job.location, 15),
                         truncate(&job.docs_used, 20),
-                        answer
+                        job.status
                     );
                 }
+                println!("{}", "-".repeat(115));
+                println!(
+                    "{} total | {} stale | run `stats` for the full report",
+                    stats.total,
+                    stats.stale.len()
+                );
             }
         }
         Commands::Export { output } => {
@@ -140,32 +516,262 @@ fn main() -> io::Result<()> {
             if let Err(e) = import_from_csv(file, &mut jobs) {
                 eprintln!("Failed to import CSV: {}", e);
             } else {
-                save_jobs(&jobs)?;
+                save_jobs(&jobs, format)?;
                 println!("Imported jobs successfully.");
             }
         }
+        Commands::Search { query, limit, threshold } => {
+            let mut results = search_jobs(&jobs, &query, threshold);
+            results.truncate(limit);
+            if results.is_empty() {
+                println!("No matches for '{}'.", query);
+            } else {
+                println!("{:<6} | {:<4} | {:<20} | {:<20} | {:<15} | {:<15}",
+                    "Score", "ID", "Company", "Title", "Location", "Status");
+                println!("{}", "-".repeat(95));
+                for (score, job) in results {
+                    println!("{:<6.2} | {:<4} | {:<20} | {:<20} | {:<15} | {:<15}",
+                        score,
+                        job.id,
+                        truncate(&job.company, 20),
+                        truncate(&job.title, 20),
+                        truncate(&job.location, 15),
+                        job.status
+                    );
+                }
+            }
+        }
+        Commands::Stats { json, stale_days } => {
+            let today = Local::now().date_naive();
+            let stats = compute_stats(&jobs, today, stale_days);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else {
+                print_stats(&stats);
+            }
+        }
     }
 
     Ok(())
 }
 
-fn load_jobs() -> io::Result<Vec<Job>> {
-    if !Path::new(DATA_FILE).exists() {
+/// Loads jobs from `format`'s data file, falling back to the most recent
+/// `.bak` snapshot if the primary file fails to deserialize.
+fn load_jobs(format: Format) -> io::Result<Vec<Job>> {
+    let path = data_file_path(format);
+    if !Path::new(path).exists() {
         return Ok(Vec::new());
     }
-    let file = File::open(DATA_FILE)?;
-    let reader = io::BufReader::new(file);
-    match serde_json::from_reader(reader) {
-        Ok(jobs) => Ok(jobs),
-        Err(_) => Ok(Vec::new()), 
+
+    let mut jobs = match std::fs::read(path).and_then(|bytes| deserialize_jobs(&bytes, format)) {
+        Ok(jobs) => jobs,
+        Err(primary_err) => {
+            eprintln!("Warning: {} is corrupt ({}), falling back to {}", path, primary_err, backup_path(path));
+            let backup = backup_path(path);
+            if !Path::new(&backup).exists() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is corrupt and no backup ({}) exists; refusing to overwrite it with an empty dataset", path, backup),
+                ));
+            }
+            match std::fs::read(&backup).and_then(|bytes| deserialize_jobs(&bytes, format)) {
+                Ok(jobs) => jobs,
+                Err(backup_err) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{} is corrupt ({}) and backup {} is also unreadable ({}); refusing to overwrite either with an empty dataset", path, primary_err, backup, backup_err),
+                    ));
+                }
+            }
+        }
+    };
+
+    for job in &mut jobs {
+        migrate_legacy_status(job);
+    }
+
+    Ok(jobs)
+}
+
+fn serialize_jobs(jobs: &[Job], format: Format) -> io::Result<Vec<u8>> {
+    match format {
+        Format::Json => serde_json::to_vec_pretty(jobs).map_err(io::Error::from),
+        Format::Msgpack => rmp_serde::to_vec(jobs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
     }
 }
 
-fn save_jobs(jobs: &[Job]) -> io::Result<()> {
-    let json = serde_json::to_string_pretty(jobs)?;
-    let mut file = File::create(DATA_FILE)?;
-    file.write_all(json.as_bytes())?;
-    Ok(())
+fn deserialize_jobs(bytes: &[u8], format: Format) -> io::Result<Vec<Job>> {
+    match format {
+        Format::Json => serde_json::from_slice(bytes).map_err(io::Error::from),
+        Format::Msgpack => rmp_serde::from_slice(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+    }
+}
+
+/// Backfills `status`/`history` on records written before the status state
+/// machine existed, from the free-text `final_answer` field they used to have.
+fn migrate_legacy_status(job: &mut Job) {
+    if !job.history.is_empty() {
+        return;
+    }
+    match job.legacy_final_answer.take() {
+        Some(answer) if !answer.is_empty() => {
+            let to = Status::from_legacy_answer(&answer);
+            job.status = to;
+            job.history.push(StatusEvent {
+                from: None,
+                to,
+                date: job.date_submitted.clone(),
+                note: Some(answer),
+            });
+        }
+        _ => {
+            job.status = Status::Applied;
+            job.history.push(StatusEvent {
+                from: None,
+                to: Status::Applied,
+                date: job.date_submitted.clone(),
+                note: None,
+            });
+        }
+    }
+}
+
+/// Writes `jobs` to `format`'s data file, rotating the previous contents into
+/// a `.bak` snapshot first and writing the new contents atomically (temp
+/// file + fsync + rename) so a crash mid-write can never corrupt the dataset.
+fn save_jobs(jobs: &[Job], format: Format) -> io::Result<()> {
+    let path = data_file_path(format);
+    if Path::new(path).exists() {
+        // Snapshot the current contents into `.bak` the same atomic way we
+        // write the primary file, so a crash mid-rotation can't leave a
+        // truncated backup behind.
+        let current = std::fs::read(path)?;
+        atomic_write(&backup_path(path), &current)?;
+    }
+    let bytes = serialize_jobs(jobs, format)?;
+    atomic_write(path, &bytes)
+}
+
+/// Writes `bytes` to a temp file next to `path`, fsyncs it, then renames it
+/// over `path` so readers only ever see a complete file.
+fn atomic_write(path: &str, bytes: &[u8]) -> io::Result<()> {
+    let target = Path::new(path);
+    let tmp_name = format!(
+        ".{}.tmp",
+        target.file_name().map(|n| n.to_string_lossy()).unwrap_or_default()
+    );
+    let tmp_path = target.with_file_name(tmp_name);
+
+    let mut tmp_file = File::create(&tmp_path)?;
+    tmp_file.write_all(bytes)?;
+    tmp_file.sync_all()?;
+    drop(tmp_file);
+
+    std::fs::rename(&tmp_path, target)
+}
+
+/// Ranks every job against `query` and returns `(score, job)` pairs scoring at
+/// least `threshold`, sorted best-first.
+///
+/// Matching is two-stage: a cheap char-bag bitmask first rules out candidate
+/// fields that are missing letters/digits the query needs, then a subsequence
+/// scorer ranks the survivors.
+fn search_jobs<'a>(jobs: &'a [Job], query: &str, threshold: f64) -> Vec<(f64, &'a Job)> {
+    let query_lower = query.to_lowercase();
+    let query_bag = char_bag(&query_lower);
+
+    let mut results: Vec<(f64, &Job)> = jobs
+        .iter()
+        .filter_map(|job| best_field_score(job, &query_lower, query_bag).map(|score| (score, job)))
+        .filter(|(score, _)| *score >= threshold)
+        .collect();
+
+    results.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    results
+}
+
+/// Best subsequence-match score across a job's company, title, location, and
+/// docs fields, or `None` if no field's char bag covers the query.
+fn best_field_score(job: &Job, query_lower: &str, query_bag: u64) -> Option<f64> {
+    let fields = [&job.company, &job.title, &job.location, &job.docs_used];
+    let mut best: Option<f64> = None;
+    for field in fields {
+        if char_bag(field) & query_bag != query_bag {
+            continue;
+        }
+        if let Some(score) = subsequence_score(field, query_lower) {
+            best = Some(best.map_or(score, |b| b.max(score)));
+        }
+    }
+    best
+}
+
+/// Sets bit `i` for each distinct lowercased ASCII letter/digit present in `s`.
+fn char_bag(s: &str) -> u64 {
+    let mut bag = 0u64;
+    for c in s.chars() {
+        if let Some(bit) = alnum_bit(c.to_ascii_lowercase()) {
+            bag |= 1 << bit;
+        }
+    }
+    bag
+}
+
+fn alnum_bit(c: char) -> Option<u32> {
+    if c.is_ascii_lowercase() {
+        Some(c as u32 - 'a' as u32)
+    } else if c.is_ascii_digit() {
+        Some(26 + (c as u32 - '0' as u32))
+    } else {
+        None
+    }
+}
+
+/// Walks `query_lower` left to right, greedily finding each character in
+/// order within `candidate`. Awards a base point per matched character, a
+/// bonus for runs of consecutive matches, and a larger bonus when a match
+/// lands at the start of a word (start of string, after a space/`-`/`_`, or
+/// on a lowercase-to-uppercase transition). The total is normalized by
+/// candidate length so short, tight matches outrank long, loose ones.
+fn subsequence_score(candidate: &str, query_lower: &str) -> Option<f64> {
+    if candidate.is_empty() || query_lower.is_empty() {
+        return None;
+    }
+    let orig: Vec<char> = candidate.chars().collect();
+    let lower: Vec<char> = orig.iter().map(|c| c.to_ascii_lowercase()).collect();
+
+    let mut score = 0.0;
+    let mut cand_idx = 0usize;
+    let mut last_matched_idx: Option<usize> = None;
+
+    for qc in query_lower.chars() {
+        let idx = loop {
+            if cand_idx >= lower.len() {
+                return None;
+            }
+            if lower[cand_idx] == qc {
+                break cand_idx;
+            }
+            cand_idx += 1;
+        };
+
+        let mut char_score = 1.0;
+        if last_matched_idx == Some(idx.wrapping_sub(1)) {
+            char_score += 0.5;
+        }
+        let starts_word = idx == 0
+            || matches!(orig[idx - 1], ' ' | '-' | '_')
+            || (orig[idx - 1].is_lowercase() && orig[idx].is_uppercase());
+        if starts_word {
+            char_score += 1.0;
+        }
+
+        score += char_score;
+        last_matched_idx = Some(idx);
+        cand_idx += 1;
+    }
+
+    Some(score / orig.len() as f64)
 }
 
 fn truncate(s: &str, max_width: usize) -> String {
@@ -180,9 +786,9 @@ fn import_from_csv(path: String, jobs: &mut Vec<Job>) -> Result<(), Box<dyn std:
     let mut rdr = csv::ReaderBuilder::new()
         .delimiter(b';')
         .from_path(path)?;
-    
+
     let mut added_count = 0;
-    
+
     for result in rdr.records() {
         let record = result?;
         // Expected header roughly: Company;Job Title;Date Submitted;Documents Used;Answer;Ort;Number
@@ -195,28 +801,40 @@ fn import_from_csv(path: String, jobs: &mut Vec<Job>) -> Result<(), Box<dyn std:
         let docs_used = record[3].trim().to_string();
         let answer_raw = record[4].trim().to_string();
         let location = record[5].trim().to_string();
-        
-        let final_answer = if answer_raw.is_empty() { None } else { Some(answer_raw) };
 
         // Check for duplicates (company + title)
         if jobs.iter().any(|j| j.company.eq_ignore_ascii_case(&company) && j.title.eq_ignore_ascii_case(&title)) {
             continue;
         }
-        
+
+        let status = if answer_raw.is_empty() {
+            Status::Applied
+        } else {
+            Status::from_legacy_answer(&answer_raw)
+        };
+        let note = if answer_raw.is_empty() { None } else { Some(answer_raw) };
+
         let id = jobs.iter().map(|j| j.id).max().unwrap_or(0) + 1;
         let job = Job {
             id,
             company,
             title,
-            date_submitted,
+            date_submitted: date_submitted.clone(),
             docs_used,
             location,
-            final_answer,
+            status,
+            history: vec![StatusEvent {
+                from: None,
+                to: status,
+                date: date_submitted,
+                note,
+            }],
+            legacy_final_answer: None,
         };
         jobs.push(job);
         added_count += 1;
     }
-    
+
     println!("Imported {} new jobs.", added_count);
     Ok(())
 }
@@ -224,7 +842,7 @@ fn export_to_pdf(jobs: &[Job], output_path: &str) -> Result<(), Box<dyn std::err
     let (doc, page1, layer1) = PdfDocument::new("Job Applications", Mm(297.0), Mm(210.0), "Layer 1");
     let font_path = "/System/Library/Fonts/Supplemental/Arial.ttf";
     let font = doc.add_external_font(File::open(font_path)?)?;
-    
+
     let mut current_layer = doc.get_page(page1).get_layer(layer1);
     let mut y = 190.0;
     let line_height = 6.0; // Reduced line height slightly for better fit
@@ -241,18 +859,18 @@ fn export_to_pdf(jobs: &[Job], output_path: &str) -> Result<(), Box<dyn std::err
             draw_header(&current_layer, &font, y);
             y -= 10.0;
         }
-        
+
         // Truncate strings to avoid overlap
         let company = truncate(&job.company, 25);
         let title = truncate(&job.title, 25);
         let _location = truncate(&job.location, 15);
-        let answer = truncate(&job.final_answer.clone().unwrap_or_else(|| "Pending".to_string()), 20);
-        
+        let status = truncate(&job.status.to_string(), 20);
+
         current_layer.use_text(job.id.to_string(), 10.0, Mm(10.0), Mm(y), &font);
         current_layer.use_text(company, 10.0, Mm(30.0), Mm(y), &font);
         current_layer.use_text(title, 10.0, Mm(80.0), Mm(y), &font);
         current_layer.use_text(&job.date_submitted, 10.0, Mm(130.0), Mm(y), &font);
-        current_layer.use_text(answer, 10.0, Mm(160.0), Mm(y), &font);
+        current_layer.use_text(status, 10.0, Mm(160.0), Mm(y), &font);
 
         y -= line_height;
     }
@@ -260,16 +878,7 @@ fn export_to_pdf(jobs: &[Job], output_path: &str) -> Result<(), Box<dyn std::err
     // --- Statistics Table ---
     y -= 10.0; // Space before stats
 
-    // Calculate stats
-    let total_jobs = jobs.len();
-    let pending_count = jobs.iter().filter(|j| j.final_answer.is_none()).count();
-    let mut status_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-    
-    for job in jobs {
-        if let Some(ans) = &job.final_answer {
-            *status_counts.entry(ans.clone()).or_insert(0) += 1;
-        }
-    }
+    let stats = compute_stats(jobs, Local::now().date_naive(), DEFAULT_STALE_DAYS);
 
     // Check space for stats header + at least a few rows
     if y < 40.0 {
@@ -284,26 +893,51 @@ fn export_to_pdf(jobs: &[Job], output_path: &str) -> Result<(), Box<dyn std::err
 
     // Draw Total
     current_layer.use_text("Total Applications", 12.0, Mm(10.0), Mm(y), &font);
-    current_layer.use_text(total_jobs.to_string(), 12.0, Mm(60.0), Mm(y), &font);
+    current_layer.use_text(stats.total.to_string(), 12.0, Mm(60.0), Mm(y), &font);
     y -= line_height;
 
-    // Draw Pending
-    current_layer.use_text("Pending", 12.0, Mm(10.0), Mm(y), &font);
-    current_layer.use_text(pending_count.to_string(), 12.0, Mm(60.0), Mm(y), &font);
-    y -= line_height;
-
-    // Draw other statuses
-    for (status, count) in status_counts {
+    // Draw counts for every known status, in pipeline order
+    for status in ALL_STATUSES {
+        let count = stats.status_counts.get(&status).copied().unwrap_or(0);
         if y < 20.0 {
             let (page, layer) = doc.add_page(Mm(297.0), Mm(210.0), "Layer 1");
             current_layer = doc.get_page(page).get_layer(layer);
             y = 190.0;
         }
-        current_layer.use_text(status, 12.0, Mm(10.0), Mm(y), &font);
+        current_layer.use_text(status.to_string(), 12.0, Mm(10.0), Mm(y), &font);
         current_layer.use_text(count.to_string(), 12.0, Mm(60.0), Mm(y), &font);
         y -= line_height;
     }
 
+    // Draw funnel and response-time metrics below the raw counts
+    if y < 30.0 {
+        let (page, layer) = doc.add_page(Mm(297.0), Mm(210.0), "Layer 1");
+        current_layer = doc.get_page(page).get_layer(layer);
+        y = 190.0;
+    }
+    current_layer.use_text(
+        format!("Interview rate: {:.1}%", stats.funnel.interview_rate * 100.0),
+        12.0, Mm(10.0), Mm(y), &font,
+    );
+    y -= line_height;
+    current_layer.use_text(
+        format!("Offer rate: {:.1}%", stats.funnel.offer_rate * 100.0),
+        12.0, Mm(10.0), Mm(y), &font,
+    );
+    y -= line_height;
+    current_layer.use_text(
+        format!("Response rate: {:.1}%", stats.response_rate * 100.0),
+        12.0, Mm(10.0), Mm(y), &font,
+    );
+    y -= line_height;
+    let avg_response = stats.avg_time_to_first_response_days
+        .map(|d| format!("{:.1} days", d))
+        .unwrap_or_else(|| "n/a".to_string());
+    current_layer.use_text(
+        format!("Avg time to first response: {}", avg_response),
+        12.0, Mm(10.0), Mm(y), &font,
+    );
+
     doc.save(&mut std::io::BufWriter::new(File::create(output_path)?))?;
     Ok(())
 }
@@ -315,3 +949,372 @@ fn draw_header(layer: &PdfLayerReference, font: &IndirectFontRef, y: f64) {
     layer.use_text("Date", 12.0, Mm(130.0), Mm(y), font);
     layer.use_text("Status", 12.0, Mm(160.0), Mm(y), font);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    // `load_jobs`/`save_jobs` operate on fixed relative paths, so tests that
+    // exercise them must serialize access to the process's current directory.
+    static CWD_LOCK: Mutex<()> = Mutex::new(());
+    static TEST_DIR_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    fn make_temp_dir(tag: &str) -> std::path::PathBuf {
+        let n = TEST_DIR_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!("job-cli-test-{}-{}-{}", std::process::id(), tag, n));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Switches into a fresh temp directory for the life of the guard and
+    /// restores the original working directory (and removes the temp dir)
+    /// on drop, even if the test panics.
+    struct TempCwd {
+        original: std::path::PathBuf,
+        dir: std::path::PathBuf,
+    }
+
+    impl TempCwd {
+        fn new(tag: &str) -> Self {
+            let dir = make_temp_dir(tag);
+            let original = std::env::current_dir().unwrap();
+            std::env::set_current_dir(&dir).unwrap();
+            TempCwd { original, dir }
+        }
+    }
+
+    impl Drop for TempCwd {
+        fn drop(&mut self) {
+            let _ = std::env::set_current_dir(&self.original);
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn test_job(id: u32, company: &str, title: &str, location: &str) -> Job {
+        Job {
+            id,
+            company: company.to_string(),
+            title: title.to_string(),
+            date_submitted: "2026-01-01".to_string(),
+            docs_used: "resume".to_string(),
+            location: location.to_string(),
+            status: Status::Applied,
+            history: vec![StatusEvent {
+                from: None,
+                to: Status::Applied,
+                date: "2026-01-01".to_string(),
+                note: None,
+            }],
+            legacy_final_answer: None,
+        }
+    }
+
+    #[test]
+    fn subsequence_score_requires_query_chars_in_order() {
+        assert!(subsequence_score("Google", "ggl").is_some());
+        assert!(subsequence_score("Google", "lgg").is_none());
+    }
+
+    #[test]
+    fn subsequence_score_rewards_word_start_matches() {
+        let word_start = subsequence_score("xx-robot", "rob").unwrap();
+        let no_boundary = subsequence_score("xxxrobot", "rob").unwrap();
+        assert!(word_start > no_boundary);
+    }
+
+    #[test]
+    fn subsequence_score_rewards_consecutive_matches() {
+        let consecutive = subsequence_score("xabxx", "ab").unwrap();
+        let scattered = subsequence_score("xaxbx", "ab").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn search_jobs_ranks_closer_matches_first() {
+        let jobs = vec![
+            test_job(1, "Globex Corporation", "Engineer", "Remote"),
+            test_job(2, "Acme", "Engineer", "Remote"),
+        ];
+        let results = search_jobs(&jobs, "globex", 0.0);
+        assert_eq!(results[0].1.id, 1);
+    }
+
+    #[test]
+    fn search_jobs_filters_by_threshold() {
+        let jobs = vec![test_job(1, "Acme", "Engineer", "Remote")];
+        let results = search_jobs(&jobs, "acme", 10.0);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn search_jobs_char_bag_prefilter_excludes_missing_letters() {
+        let jobs = vec![test_job(1, "Acme", "Engineer", "Remote")];
+        let results = search_jobs(&jobs, "zzz", 0.0);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn compute_stats_funnel_counts_reached_stages() {
+        let applied_only = test_job(1, "A", "T", "Remote");
+
+        let mut interviewed = test_job(2, "B", "T", "Remote");
+        interviewed.status = Status::Interviewing;
+        interviewed.history.push(StatusEvent {
+            from: Some(Status::Applied),
+            to: Status::Interviewing,
+            date: "2026-01-05".to_string(),
+            note: None,
+        });
+
+        let mut offered = test_job(3, "C", "T", "Remote");
+        offered.status = Status::Offer;
+        offered.history.push(StatusEvent {
+            from: Some(Status::Applied),
+            to: Status::Interviewing,
+            date: "2026-01-05".to_string(),
+            note: None,
+        });
+        offered.history.push(StatusEvent {
+            from: Some(Status::Interviewing),
+            to: Status::Offer,
+            date: "2026-01-10".to_string(),
+            note: None,
+        });
+
+        let jobs = vec![applied_only, interviewed, offered];
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let stats = compute_stats(&jobs, today, 14);
+
+        assert_eq!(stats.funnel.applied, 3);
+        assert_eq!(stats.funnel.interviewing, 2);
+        assert_eq!(stats.funnel.offer, 1);
+        assert!((stats.funnel.interview_rate - (2.0 / 3.0)).abs() < 1e-9);
+        assert!((stats.funnel.offer_rate - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn compute_stats_response_rate_and_avg_time_to_first_response() {
+        let mut responded = test_job(1, "A", "T", "Remote");
+        responded.status = Status::Screening;
+        responded.history.push(StatusEvent {
+            from: Some(Status::Applied),
+            to: Status::Screening,
+            date: "2026-01-06".to_string(),
+            note: None,
+        });
+
+        let not_responded = test_job(2, "B", "T", "Remote");
+
+        let jobs = vec![responded, not_responded];
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let stats = compute_stats(&jobs, today, 14);
+
+        assert!((stats.response_rate - 0.5).abs() < 1e-9);
+        assert_eq!(stats.avg_time_to_first_response_days, Some(5.0));
+    }
+
+    #[test]
+    fn compute_stats_stale_boundary_is_inclusive_and_skips_terminal_jobs() {
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+
+        let mut exactly_stale = test_job(1, "A", "T", "Remote");
+        exactly_stale.date_submitted = "2026-01-18".to_string();
+        exactly_stale.history = vec![StatusEvent {
+            from: None,
+            to: Status::Applied,
+            date: "2026-01-18".to_string(),
+            note: None,
+        }];
+
+        let mut not_yet_stale = test_job(2, "B", "T", "Remote");
+        not_yet_stale.date_submitted = "2026-01-19".to_string();
+        not_yet_stale.history = vec![StatusEvent {
+            from: None,
+            to: Status::Applied,
+            date: "2026-01-19".to_string(),
+            note: None,
+        }];
+
+        let mut old_but_terminal = test_job(3, "C", "T", "Remote");
+        old_but_terminal.status = Status::Rejected;
+        old_but_terminal.date_submitted = "2026-01-01".to_string();
+        old_but_terminal.history = vec![StatusEvent {
+            from: Some(Status::Applied),
+            to: Status::Rejected,
+            date: "2026-01-01".to_string(),
+            note: None,
+        }];
+
+        let jobs = vec![exactly_stale, not_yet_stale, old_but_terminal];
+        let stats = compute_stats(&jobs, today, 14);
+
+        let stale_ids: Vec<u32> = stats.stale.iter().map(|j| j.id).collect();
+        assert_eq!(stale_ids, vec![1]);
+    }
+
+    #[test]
+    fn is_valid_transition_matches_pipeline_matrix() {
+        use Status::*;
+        assert!(is_valid_transition(Applied, Screening));
+        assert!(is_valid_transition(Applied, Interviewing));
+        assert!(is_valid_transition(Applied, Offer));
+        assert!(is_valid_transition(Applied, Rejected));
+        assert!(is_valid_transition(Applied, Withdrawn));
+        assert!(!is_valid_transition(Applied, Accepted));
+
+        assert!(is_valid_transition(Screening, Interviewing));
+        assert!(!is_valid_transition(Screening, Applied));
+
+        assert!(is_valid_transition(Interviewing, Offer));
+        assert!(is_valid_transition(Interviewing, Screening));
+        assert!(!is_valid_transition(Interviewing, Applied));
+
+        assert!(is_valid_transition(Offer, Accepted));
+        assert!(is_valid_transition(Offer, Rejected));
+        assert!(!is_valid_transition(Offer, Interviewing));
+
+        assert!(!is_valid_transition(Applied, Applied));
+    }
+
+    #[test]
+    fn is_valid_transition_terminal_states_require_force_to_leave() {
+        // Terminal states never appear as a `true` source in the matrix; the
+        // CLI's `--force` flag (see the `!force && !is_valid_transition(...)`
+        // guard in `Commands::Status`) is the only way to move out of one,
+        // e.g. `Rejected -> Interviewing` is rejected here and only allowed
+        // by the caller when `--force` is passed.
+        use Status::*;
+        for terminal in [Rejected, Accepted, Withdrawn] {
+            for target in ALL_STATUSES {
+                if terminal != target {
+                    assert!(
+                        !is_valid_transition(terminal, target),
+                        "{:?} -> {:?} should require --force",
+                        terminal,
+                        target
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_legacy_answer_prioritizes_terminal_keywords_over_earlier_stage_mentions() {
+        assert_eq!(Status::from_legacy_answer("Rejected after the interview round"), Status::Rejected);
+        assert_eq!(Status::from_legacy_answer("Declined after screening"), Status::Rejected);
+        assert_eq!(Status::from_legacy_answer("Withdrew after getting an offer"), Status::Withdrawn);
+        assert_eq!(Status::from_legacy_answer("Accepted"), Status::Accepted);
+        assert_eq!(Status::from_legacy_answer("Got an offer"), Status::Offer);
+        assert_eq!(Status::from_legacy_answer("Phone screen scheduled"), Status::Screening);
+    }
+
+    #[test]
+    fn migrate_legacy_status_converts_old_final_answer_into_history() {
+        let mut job = test_job(1, "Acme", "Engineer", "Remote");
+        job.history = Vec::new();
+        job.legacy_final_answer = Some("Rejected after the interview round".to_string());
+
+        migrate_legacy_status(&mut job);
+
+        assert_eq!(job.status, Status::Rejected);
+        assert_eq!(job.history.len(), 1);
+        assert_eq!(job.history[0].to, Status::Rejected);
+        assert_eq!(job.history[0].note.as_deref(), Some("Rejected after the interview round"));
+        assert!(job.legacy_final_answer.is_none());
+    }
+
+    #[test]
+    fn migrate_legacy_status_defaults_to_applied_when_no_legacy_answer() {
+        let mut job = test_job(1, "Acme", "Engineer", "Remote");
+        job.history = Vec::new();
+        job.legacy_final_answer = None;
+
+        migrate_legacy_status(&mut job);
+
+        assert_eq!(job.status, Status::Applied);
+        assert_eq!(job.history.len(), 1);
+        assert!(job.history[0].from.is_none());
+    }
+
+    #[test]
+    fn atomic_write_fully_replaces_primary_and_leaves_other_files_untouched() {
+        let dir = make_temp_dir("atomic-write");
+        let primary = dir.join("jobs.json");
+        let backup = format!("{}.bak", primary.to_str().unwrap());
+
+        // Simulate a prior primary + backup already on disk before the write.
+        std::fs::write(&primary, b"old-primary").unwrap();
+        std::fs::write(&backup, b"old-backup").unwrap();
+
+        atomic_write(primary.to_str().unwrap(), b"new-primary-contents").unwrap();
+
+        assert_eq!(std::fs::read(&primary).unwrap(), b"new-primary-contents");
+        // atomic_write only ever touches the path it's given via temp+rename.
+        assert_eq!(std::fs::read(&backup).unwrap(), b"old-backup");
+        assert!(!dir.join(".jobs.json.tmp").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn save_jobs_rotates_previous_contents_into_backup_atomically() {
+        let _guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let _cwd = TempCwd::new("save-rotation");
+
+        let first = vec![test_job(1, "Acme", "Engineer", "Remote")];
+        save_jobs(&first, Format::Json).unwrap();
+        assert!(!Path::new(&backup_path(JSON_DATA_FILE)).exists());
+
+        let second = vec![test_job(1, "Acme", "Engineer", "Remote"), test_job(2, "Globex", "PM", "NYC")];
+        save_jobs(&second, Format::Json).unwrap();
+
+        let backup_bytes = std::fs::read(backup_path(JSON_DATA_FILE)).unwrap();
+        let backed_up = deserialize_jobs(&backup_bytes, Format::Json).unwrap();
+        assert_eq!(backed_up.len(), 1);
+
+        let primary_bytes = std::fs::read(JSON_DATA_FILE).unwrap();
+        let current = deserialize_jobs(&primary_bytes, Format::Json).unwrap();
+        assert_eq!(current.len(), 2);
+    }
+
+    #[test]
+    fn load_jobs_falls_back_to_backup_on_primary_corruption() {
+        let _guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let _cwd = TempCwd::new("load-fallback");
+
+        let jobs = vec![test_job(1, "Acme", "Engineer", "Remote")];
+        save_jobs(&jobs, Format::Json).unwrap();
+        save_jobs(&jobs, Format::Json).unwrap(); // second save rotates v1 into .bak
+
+        std::fs::write(JSON_DATA_FILE, b"{not valid json").unwrap();
+
+        let loaded = load_jobs(Format::Json).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].company, "Acme");
+    }
+
+    #[test]
+    fn load_jobs_errors_when_primary_and_backup_are_both_corrupt() {
+        let _guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let _cwd = TempCwd::new("load-double-failure");
+
+        std::fs::write(JSON_DATA_FILE, b"{not valid json").unwrap();
+        std::fs::write(backup_path(JSON_DATA_FILE), b"also not valid").unwrap();
+
+        let result = load_jobs(Format::Json);
+        assert!(result.is_err(), "expected an error instead of a silent empty Vec");
+    }
+
+    #[test]
+    fn resolve_format_auto_detects_msgpack_file_over_default() {
+        let _guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let _cwd = TempCwd::new("resolve-format");
+
+        assert_eq!(resolve_format(None), Format::Json);
+        std::fs::write(MSGPACK_DATA_FILE, b"x").unwrap();
+        assert_eq!(resolve_format(None), Format::Msgpack);
+        assert_eq!(resolve_format(Some(Format::Json)), Format::Json);
+    }
+}